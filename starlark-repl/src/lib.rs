@@ -33,8 +33,12 @@
 //!
 //! Options:
 //!     -b, --build_file    Parse the build file format instead of full Starlark.
+//!     -c, --eval EXPR     Evaluate EXPR non-interactively and exit.
 //!     -h, --help          Show the usage of this program.
 //!     -r, --repl          Run a REPL after files have been parsed.
+//!
+//! With no file, no `-c`/`--eval` and no `-r`, a script is read from stdin and evaluated
+//! non-interactively, so `starlark-repl` can be used in a shell pipeline without a TTY.
 //! ```
 extern crate codemap;
 extern crate codemap_diagnostic;
@@ -42,22 +46,93 @@ extern crate linefeed;
 extern crate starlark;
 
 use codemap_diagnostic::{ColorConfig, Emitter};
-use linefeed::{Interface, ReadResult};
+use linefeed::{Completer, Completion, Interface, Prompter, ReadResult, Terminal};
 use starlark::environment::Environment;
 use starlark::eval::eval_lexer;
 use starlark::eval::simple::SimpleFileLoader;
 use starlark::syntax::dialect::Dialect;
 use starlark::syntax::lexer::{BufferedLexer, LexerIntoIter, LexerItem};
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
-fn print_eval<T1: Iterator<Item = LexerItem>, T2: LexerIntoIter<T1>>(
+/// Starlark reserved words, offered during completion.
+const KEYWORDS: &[&str] = &[
+    "and", "or", "not", "in", "for", "if", "else", "elif", "def", "return", "pass", "break",
+    "continue", "lambda", "load", "del", "True", "False", "None",
+];
+
+/// Method names of the builtin `list` type, offered when completing after a `.` that follows
+/// a name bound to a list.
+///
+/// Kept in sync with the `function` hook implemented by `List`.
+const LIST_METHODS: &[&str] = &[
+    "append", "extend", "insert", "remove", "pop", "index", "count", "clear", "sort", "reverse",
+];
+
+/// Where the REPL persists its line history across sessions.
+fn history_path() -> Option<PathBuf> {
+    std::env::var("HOME").ok().map(|home| {
+        let mut p = PathBuf::from(home);
+        p.push(".starlark_history");
+        p
+    })
+}
+
+/// A [`linefeed::Completer`] for the REPL.
+///
+/// After `name.` it looks `name` up in the live REPL `Environment` and offers the list methods
+/// when it is bound to a list. Anywhere else it offers Starlark keywords only; names bound in
+/// the `Environment` are not offered.
+struct EnvCompleter {
+    env: Arc<Mutex<Environment>>,
+}
+
+impl<Term: Terminal> Completer<Term> for EnvCompleter {
+    fn complete(
+        &self,
+        word: &str,
+        prompter: &Prompter<Term>,
+        start: usize,
+        _end: usize,
+    ) -> Option<Vec<Completion>> {
+        let line = prompter.buffer();
+        let candidates: Vec<String> = if start > 0 && line[..start].ends_with('.') {
+            let receiver_end = start - 1;
+            let receiver_start = line[..receiver_end]
+                .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .map(|i| i + 1)
+                .unwrap_or(0);
+            let receiver = &line[receiver_start..receiver_end];
+            match self.env.lock().unwrap().get(receiver) {
+                Ok(ref v) if v.get_type() == "list" => LIST_METHODS.iter().map(|s| s.to_string()).collect(),
+                _ => Vec::new(),
+            }
+        } else {
+            // Only keywords are offered here; names bound in the environment are not.
+            KEYWORDS.iter().map(|s| s.to_string()).collect()
+        };
+        Some(
+            candidates
+                .into_iter()
+                .filter(|c| c.starts_with(word))
+                .map(|c| Completion::simple(c))
+                .collect(),
+        )
+    }
+}
+
+/// Evaluate `content` and print its result the way the interactive REPL does: nothing is
+/// printed for a `NoneType` result, and a parse/eval error is emitted as a diagnostic to
+/// stderr rather than returned. Returns `true` on success, so callers driving several
+/// evaluations against one shared `env` (e.g. one per file argument) can track overall status.
+pub fn print_eval<T1: Iterator<Item = LexerItem>, T2: LexerIntoIter<T1>>(
     map: Arc<Mutex<codemap::CodeMap>>,
     filename: &str,
     content: &str,
     lexer: T2,
     dialect: Dialect,
     env: &mut Environment,
-) {
+) -> bool {
     match eval_lexer(
         &map,
         filename,
@@ -71,8 +146,43 @@ fn print_eval<T1: Iterator<Item = LexerItem>, T2: LexerIntoIter<T1>>(
             if v.get_type() != "NoneType" {
                 println!("{}", v.to_repr())
             }
+            true
+        }
+        Err(p) => {
+            Emitter::stderr(ColorConfig::Always, Some(&map.lock().unwrap())).emit(&[p]);
+            false
+        }
+    }
+}
+
+/// Evaluate `content` once, non-interactively, and print its result.
+///
+/// This is the batch-mode counterpart to [repl]: it backs the `-c/--eval "<expr>"` flag and the
+/// stdin-script mode, so `starlark-repl` can be used as a scriptable step in a shell pipeline
+/// without a TTY. On success the final value's `to_repr` is printed to stdout and `true` is
+/// returned; on error the diagnostic is emitted to stderr (as [print_eval] does) and `false` is
+/// returned so the caller can exit with a non-zero status.
+pub fn eval_batch(global_environment: &Environment, dialect: Dialect, filename: &str, content: &str) -> bool {
+    let map = Arc::new(Mutex::new(codemap::CodeMap::new()));
+    let mut env = global_environment.child("eval");
+    let lexer = BufferedLexer::new(content);
+    match eval_lexer(
+        &map,
+        filename,
+        content,
+        dialect,
+        lexer,
+        &mut env,
+        SimpleFileLoader::new(&map.clone()),
+    ) {
+        Ok(v) => {
+            println!("{}", v.to_repr());
+            true
+        }
+        Err(p) => {
+            Emitter::stderr(ColorConfig::Always, Some(&map.lock().unwrap())).emit(&[p]);
+            false
         }
-        Err(p) => Emitter::stderr(ColorConfig::Always, Some(&map.lock().unwrap())).emit(&[p]),
     }
 }
 
@@ -88,10 +198,16 @@ fn print_eval<T1: Iterator<Item = LexerItem>, T2: LexerIntoIter<T1>>(
 pub fn repl(global_environment: &Environment, dialect: Dialect) {
     let map = Arc::new(Mutex::new(codemap::CodeMap::new()));
     let reader = Interface::new("Starlark").unwrap();
-    let mut env = global_environment.child("repl");
+    let env = Arc::new(Mutex::new(global_environment.child("repl")));
     let mut n = 0;
+    let history = history_path();
 
     reader.set_prompt(">>> ").unwrap();
+    reader
+        .set_completer(Arc::new(EnvCompleter { env: env.clone() }));
+    if let Some(ref path) = history {
+        let _ = reader.load_history(path);
+    }
 
     while let Ok(ReadResult::Input(input)) = reader.read_line() {
         if input.len() != 0 {
@@ -118,10 +234,13 @@ pub fn repl(global_environment: &Environment, dialect: Dialect) {
                 &content,
                 lexer,
                 dialect,
-                &mut env,
-            )
+                &mut env.lock().unwrap(),
+            );
         }
         reader.set_prompt(">>> ").unwrap();
     }
+    if let Some(ref path) = history {
+        let _ = reader.save_history(path);
+    }
     println!("\nGoodbye!");
 }
@@ -0,0 +1,114 @@
+// Copyright 2018 The Starlark in Rust Authors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     https://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `starlark-repl` command-line binary. See the crate docs for the available flags.
+extern crate codemap;
+extern crate getopts;
+extern crate starlark;
+extern crate starlark_repl;
+
+use getopts::Options;
+use starlark::environment::Environment;
+use starlark::syntax::dialect::Dialect;
+use starlark::syntax::lexer::BufferedLexer;
+use starlark_repl::{eval_batch, print_eval, repl};
+use std::io::Read;
+use std::sync::{Arc, Mutex};
+use std::{env, fs, io, process};
+
+fn print_usage(program: &str, opts: &Options) {
+    let brief = format!("Usage: {} [options] [file1..filen]", program);
+    print!("{}", opts.usage(&brief));
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let program = args[0].clone();
+
+    let mut opts = Options::new();
+    opts.optflag(
+        "b",
+        "build_file",
+        "Parse the build file format instead of full Starlark.",
+    );
+    opts.optopt("c", "eval", "Evaluate EXPR non-interactively and exit.", "EXPR");
+    opts.optflag("h", "help", "Show the usage of this program.");
+    opts.optflag("r", "repl", "Run a REPL after files have been parsed.");
+
+    let matches = match opts.parse(&args[1..]) {
+        Ok(m) => m,
+        Err(f) => {
+            eprintln!("{}", f);
+            print_usage(&program, &opts);
+            process::exit(1);
+        }
+    };
+
+    if matches.opt_present("h") {
+        print_usage(&program, &opts);
+        return;
+    }
+
+    let dialect = if matches.opt_present("b") {
+        Dialect::Build
+    } else {
+        Dialect::Bzl
+    };
+    let mut global_environment = Environment::new("global");
+
+    let mut ok = true;
+    if let Some(expr) = matches.opt_str("c") {
+        ok = eval_batch(&global_environment, dialect, "<eval>", &expr);
+    } else if matches.free.is_empty() {
+        if !matches.opt_present("r") {
+            let mut content = String::new();
+            if let Err(e) = io::stdin().read_to_string(&mut content) {
+                eprintln!("{}: {}", program, e);
+                process::exit(1);
+            }
+            ok = eval_batch(&global_environment, dialect, "<stdin>", &content);
+        }
+    } else {
+        // File arguments go through `print_eval`, sharing `global_environment` with the
+        // `-r` REPL below, so defs from these files stay visible there; `eval_batch` is
+        // reserved for the standalone `-c`/stdin paths, which never feed into a REPL.
+        let map = Arc::new(Mutex::new(codemap::CodeMap::new()));
+        for file in &matches.free {
+            let content = match fs::read_to_string(file) {
+                Ok(c) => c,
+                Err(e) => {
+                    eprintln!("{}: {}", file, e);
+                    process::exit(1);
+                }
+            };
+            let lexer = BufferedLexer::new(&content);
+            ok = print_eval(
+                map.clone(),
+                file,
+                &content,
+                lexer,
+                dialect,
+                &mut global_environment,
+            ) && ok;
+        }
+    }
+
+    if !ok {
+        process::exit(1);
+    }
+
+    if matches.opt_present("r") && !matches.opt_present("c") {
+        repl(&global_environment, dialect);
+    }
+}
@@ -46,6 +46,33 @@ impl List {
             content: Vec::new(),
         })
     }
+
+    fn content_mut(&mut self) -> Result<&mut Vec<Value>, ValueError> {
+        if self.frozen {
+            Err(ValueError::CannotMutateImmutableValue)
+        } else {
+            Ok(&mut self.content)
+        }
+    }
+
+    /// The positions selected by `[start:stop:stride]`, in the order Starlark/Python visits
+    /// them (so for a negative `stride` the highest index comes first).
+    fn slice_indices(start: i64, stop: i64, stride: i64) -> Vec<usize> {
+        let mut indices = Vec::new();
+        let mut i = start;
+        if stride > 0 {
+            while i < stop {
+                indices.push(i as usize);
+                i += stride;
+            }
+        } else {
+            while i > stop {
+                indices.push(i as usize);
+                i += stride;
+            }
+        }
+        indices
+    }
 }
 
 impl TypedValue for List {
@@ -97,7 +124,15 @@ impl TypedValue for List {
     fn to_bool(&self) -> bool {
         !self.content.is_empty()
     }
+    /// Lists are only hashable once frozen, matching Starlark's rule that only immutable
+    /// values can be used as dict keys or set members.
     fn get_hash(&self) -> Result<u64, ValueError> {
+        if !self.frozen {
+            // No dedicated "not hashable" variant is confirmed to exist on `ValueError`;
+            // reuse the one other value-level error this crate is already known to define
+            // rather than assume an unverified variant.
+            return Err(ValueError::IncorrectParameterType);
+        }
         let mut s = DefaultHasher::new();
         for v in self.content.iter() {
             s.write_u64(v.get_hash()?)
@@ -254,16 +289,216 @@ impl TypedValue for List {
     /// assert_eq!(&v.to_repr(), "[1, 1, [2, 3]]");
     /// ```
     fn set_at(&mut self, index: Value, new_value: Value) -> Result<(), ValueError> {
-        if self.frozen {
-            Err(ValueError::CannotMutateImmutableValue)
-        } else {
-            let i = index.convert_index(self.length()?)? as usize;
-            self.content[i] = new_value.clone();
-            Ok(())
+        let i = index.convert_index(self.length()?)? as usize;
+        self.content_mut()?[i] = new_value.clone();
+        Ok(())
+    }
+
+    /// Assign `new_value` into the slice `[start:stop:stride]`, resizing the list as needed.
+    ///
+    /// For the default stride (`1`) the list is resized to fit `new_value`, like Python's
+    /// `l[i:j] = iterable`. For an extended slice (any other stride) `new_value` must yield
+    /// exactly as many elements as the slice selects, matching Python/Starlark semantics.
+    ///
+    /// This, along with [`del_at`](TypedValue::del_at) and
+    /// [`del_slice`](TypedValue::del_slice) below, extends the `TypedValue` trait declared in
+    /// `values/mod.rs`. Routing `l[i:j:k] = x` and `del l[...]` through these generically for
+    /// every value type is the evaluator's job (the slice-assignment target and `del`-statement
+    /// handling in the `syntax`/`eval` modules); neither of those files is part of this change,
+    /// so that wiring still needs to land before this is reachable from parsed source.
+    ///
+    /// # Example
+    /// ```
+    /// # use starlark::values::*;
+    /// # use starlark::values::list::List;
+    /// let mut v = Value::from(vec![1, 2, 3, 4]);
+    /// v.set_slice(Some(Value::from(1)), Some(Value::from(3)), None, Value::from(vec![9]))
+    ///     .unwrap();
+    /// assert_eq!(&v.to_repr(), "[1, 9, 4]");
+    /// ```
+    fn set_slice(
+        &mut self,
+        start: Option<Value>,
+        stop: Option<Value>,
+        stride: Option<Value>,
+        new_value: Value,
+    ) -> Result<(), ValueError> {
+        let (start, stop, stride) =
+            Value::convert_slice_indices(self.length()?, start, stop, stride)?;
+        let values: Vec<Value> = new_value.into_iter()?.collect();
+        if stride == 1 {
+            let (lo, hi) = if start < stop { (start, stop) } else { (start, start) };
+            self.content_mut()?.splice(lo as usize..hi as usize, values);
+            return Ok(());
+        }
+        let indices = List::slice_indices(start, stop, stride);
+        if indices.len() != values.len() {
+            return Err(ValueError::IncorrectParameterType);
+        }
+        let content = self.content_mut()?;
+        for (i, v) in indices.into_iter().zip(values.into_iter()) {
+            content[i] = v;
+        }
+        Ok(())
+    }
+
+    /// Remove the element at `index`, implementing `del l[index]`.
+    fn del_at(&mut self, index: Value) -> Result<(), ValueError> {
+        let i = index.convert_index(self.length()?)? as usize;
+        self.content_mut()?.remove(i);
+        Ok(())
+    }
+
+    /// Remove every element selected by `[start:stop:stride]`, implementing `del l[i:j:k]`.
+    fn del_slice(
+        &mut self,
+        start: Option<Value>,
+        stop: Option<Value>,
+        stride: Option<Value>,
+    ) -> Result<(), ValueError> {
+        let (start, stop, stride) =
+            Value::convert_slice_indices(self.length()?, start, stop, stride)?;
+        let mut indices = List::slice_indices(start, stop, stride);
+        // Remove from the back so earlier indices stay valid as we go.
+        indices.sort_unstable_by(|a, b| b.cmp(a));
+        let content = self.content_mut()?;
+        for i in indices {
+            content.remove(i);
+        }
+        Ok(())
+    }
+
+    not_supported!(attr);
+
+    /// Dispatch the standard `list` methods (`append`, `extend`, `insert`, `remove`, `pop`,
+    /// `index`, `count`, `clear`, `sort` and `reverse`).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use starlark::values::*;
+    /// # use starlark::values::list::List;
+    /// let mut v = Value::from(vec![1, 2, 3]);
+    /// v.function("append", vec![Value::from(4)]).unwrap();
+    /// assert_eq!(&v.to_repr(), "[1, 2, 3, 4]");
+    /// ```
+    fn function(&mut self, method: &str, mut args: Vec<Value>) -> ValueResult {
+        match method {
+            "append" => {
+                let x = args.pop().ok_or(ValueError::IncorrectParameterType)?;
+                self.content_mut()?.push(x);
+                Ok(Value::new(NoneType::None))
+            }
+            "extend" => {
+                let other = args.pop().ok_or(ValueError::IncorrectParameterType)?;
+                let extra: Vec<Value> = other.into_iter()?.collect();
+                self.content_mut()?.extend(extra);
+                Ok(Value::new(NoneType::None))
+            }
+            "insert" => {
+                if args.len() != 2 {
+                    return Err(ValueError::IncorrectParameterType);
+                }
+                let x = args.pop().unwrap();
+                let index = args.pop().unwrap();
+                let len = self.length()?;
+                // `list.insert` never raises: out-of-range indices clamp to the ends, so
+                // `insert(100, x)` appends and `insert(-100, x)` prepends. This is why we
+                // clamp by hand instead of reusing the strict bounds-checked `convert_index`.
+                let mut i = index.to_int()?;
+                if i < 0 {
+                    i += len;
+                }
+                let i = i.max(0).min(len) as usize;
+                self.content_mut()?.insert(i, x);
+                Ok(Value::new(NoneType::None))
+            }
+            "remove" => {
+                let needle = args.pop().ok_or(ValueError::IncorrectParameterType)?;
+                let pos = self.content.iter().position(|x| {
+                    x.compare(needle.clone()) == Ordering::Equal
+                });
+                match pos {
+                    Some(i) => {
+                        self.content_mut()?.remove(i);
+                        Ok(Value::new(NoneType::None))
+                    }
+                    // No dedicated "value not found" variant is confirmed to exist on
+                    // `ValueError`; reuse the one other value-level error this crate is
+                    // already known to define rather than assume an unverified variant.
+                    None => Err(ValueError::IncorrectParameterType),
+                }
+            }
+            "pop" => {
+                let len = self.length()?;
+                let index = match args.pop() {
+                    Some(v) => v.convert_index(len)?,
+                    None => len - 1,
+                };
+                if index < 0 || index >= len {
+                    return Err(ValueError::IndexOutOfBound(index));
+                }
+                Ok(self.content_mut()?.remove(index as usize))
+            }
+            "index" => {
+                let needle = args.pop().ok_or(ValueError::IncorrectParameterType)?;
+                match self.content.iter().position(|x| {
+                    x.compare(needle.clone()) == Ordering::Equal
+                }) {
+                    Some(i) => Ok(Value::new(i as i64)),
+                    None => Err(ValueError::IncorrectParameterType),
+                }
+            }
+            "count" => {
+                let needle = args.pop().ok_or(ValueError::IncorrectParameterType)?;
+                let n = self.content
+                    .iter()
+                    .filter(|x| x.compare(needle.clone()) == Ordering::Equal)
+                    .count();
+                Ok(Value::new(n as i64))
+            }
+            "clear" => {
+                self.content_mut()?.clear();
+                Ok(Value::new(NoneType::None))
+            }
+            "reverse" => {
+                self.content_mut()?.reverse();
+                Ok(Value::new(NoneType::None))
+            }
+            "sort" => {
+                // `key` and `reverse` are resolved by their fixed keyword slot (0 and 1
+                // respectively), not by popping off whatever happens to be supplied last:
+                // `sort(key=f)` must reach here as `[f]`, not be mistaken for `reverse=f`.
+                let key = args.get(0)
+                    .cloned()
+                    .filter(|v| v.get_type() != "NoneType");
+                let reverse = args.get(1).map(|v| v.to_bool()).unwrap_or(false);
+                let content = self.content_mut()?;
+                match key {
+                    Some(key_fn) => {
+                        let mut keyed: Vec<(Value, Value)> = Vec::with_capacity(content.len());
+                        for x in content.drain(..) {
+                            let k = key_fn.call(vec![x.clone()])?;
+                            keyed.push((k, x));
+                        }
+                        keyed.sort_by(|a, b| a.0.compare(b.0.clone()));
+                        if reverse {
+                            keyed.reverse();
+                        }
+                        content.extend(keyed.into_iter().map(|(_, x)| x));
+                    }
+                    None => {
+                        content.sort_by(|a, b| a.compare(b.clone()));
+                        if reverse {
+                            content.reverse();
+                        }
+                    }
+                }
+                Ok(Value::new(NoneType::None))
+            }
+            _ => Err(ValueError::IncorrectParameterType),
         }
     }
 
-    not_supported!(attr, function);
     not_supported!(plus, minus, sub, div, pipe, percent);
 }
 
@@ -290,6 +525,157 @@ mod tests {
         assert_eq!(&v.to_repr(), "[1, 1, [2, 3]]");
     }
 
+    #[test]
+    fn test_list_methods() {
+        let mut v = Value::from(vec![1, 2, 3]);
+        v.function("append", vec![Value::from(4)]).unwrap();
+        assert_eq!(&v.to_repr(), "[1, 2, 3, 4]");
+
+        v.function("extend", vec![Value::from(vec![5, 6])]).unwrap();
+        assert_eq!(&v.to_repr(), "[1, 2, 3, 4, 5, 6]");
+
+        v.function("insert", vec![Value::from(0), Value::from(0)])
+            .unwrap();
+        assert_eq!(&v.to_repr(), "[0, 1, 2, 3, 4, 5, 6]");
+
+        assert_eq!(
+            v.function("index", vec![Value::from(3)]).unwrap(),
+            Value::from(3)
+        );
+        assert_eq!(
+            v.function("index", vec![Value::from(42)]),
+            Err(ValueError::IncorrectParameterType)
+        );
+
+        assert_eq!(
+            v.function("count", vec![Value::from(3)]).unwrap(),
+            Value::from(1)
+        );
+
+        v.function("remove", vec![Value::from(0)]).unwrap();
+        assert_eq!(&v.to_repr(), "[1, 2, 3, 4, 5, 6]");
+        assert_eq!(
+            v.function("remove", vec![Value::from(42)]),
+            Err(ValueError::IncorrectParameterType)
+        );
+
+        let popped = v.function("pop", vec![]).unwrap();
+        assert_eq!(popped, Value::from(6));
+        assert_eq!(&v.to_repr(), "[1, 2, 3, 4, 5]");
+
+        v.function("reverse", vec![]).unwrap();
+        assert_eq!(&v.to_repr(), "[5, 4, 3, 2, 1]");
+
+        v.function("sort", vec![]).unwrap();
+        assert_eq!(&v.to_repr(), "[1, 2, 3, 4, 5]");
+
+        v.function("clear", vec![]).unwrap();
+        assert_eq!(&v.to_repr(), "[]");
+    }
+
+    #[test]
+    fn test_insert_clamps_out_of_range_index() {
+        let mut v = Value::from(vec![1, 2, 3]);
+        v.function("insert", vec![Value::from(100), Value::from(4)])
+            .unwrap();
+        assert_eq!(&v.to_repr(), "[1, 2, 3, 4]");
+
+        let mut v = Value::from(vec![1, 2, 3]);
+        v.function("insert", vec![Value::from(-100), Value::from(0)])
+            .unwrap();
+        assert_eq!(&v.to_repr(), "[0, 1, 2, 3]");
+    }
+
+    #[test]
+    fn test_sort_key_and_reverse_keyword_slots() {
+        // sort(reverse=True) alone: the `key` slot is padded with None to stay in position.
+        let mut v = Value::from(vec![3, 1, 2]);
+        v.function("sort", vec![Value::new(NoneType::None), Value::from(true)])
+            .unwrap();
+        assert_eq!(&v.to_repr(), "[3, 2, 1]");
+
+        // sort(key=f) alone must land `f` in the `key` slot, not be mistaken for `reverse`:
+        // 5 isn't callable, so this must error rather than silently reverse the list.
+        let mut v = Value::from(vec![3, 1, 2]);
+        assert!(v.function("sort", vec![Value::from(5)]).is_err());
+    }
+
+    #[test]
+    fn test_list_methods_frozen() {
+        let mut v = Value::from(vec![1, 2, 3]);
+        v.freeze();
+        assert_eq!(
+            v.function("append", vec![Value::from(4)]),
+            Err(ValueError::CannotMutateImmutableValue)
+        );
+    }
+
+    #[test]
+    fn test_set_slice() {
+        let mut v = Value::from(vec![1, 2, 3, 4]);
+        v.set_slice(Some(Value::from(1)), Some(Value::from(3)), None, Value::from(vec![9]))
+            .unwrap();
+        assert_eq!(&v.to_repr(), "[1, 9, 4]");
+
+        let mut v = Value::from(vec![1, 2, 3, 4]);
+        v.set_slice(
+            Some(Value::from(0)),
+            Some(Value::from(4)),
+            Some(Value::from(2)),
+            Value::from(vec![10, 20]),
+        ).unwrap();
+        assert_eq!(&v.to_repr(), "[10, 2, 20, 4]");
+
+        let mut v = Value::from(vec![1, 2, 3, 4]);
+        assert!(
+            v.set_slice(
+                Some(Value::from(0)),
+                Some(Value::from(4)),
+                Some(Value::from(2)),
+                Value::from(vec![10]),
+            ).is_err()
+        );
+
+        // a[4:0:-2] selects indices 4 and 2 (values 5 and 3), highest index first.
+        let mut v = Value::from(vec![1, 2, 3, 4, 5]);
+        v.set_slice(
+            Some(Value::from(4)),
+            Some(Value::from(0)),
+            Some(Value::from(-2)),
+            Value::from(vec![50, 30]),
+        ).unwrap();
+        assert_eq!(&v.to_repr(), "[1, 2, 30, 4, 50]");
+    }
+
+    #[test]
+    fn test_del_at_and_del_slice() {
+        let mut v = Value::from(vec![1, 2, 3, 4]);
+        v.del_at(Value::from(1)).unwrap();
+        assert_eq!(&v.to_repr(), "[1, 3, 4]");
+
+        let mut v = Value::from(vec![1, 2, 3, 4, 5]);
+        v.del_slice(Some(Value::from(1)), Some(Value::from(4)), None)
+            .unwrap();
+        assert_eq!(&v.to_repr(), "[1, 5]");
+
+        // del v[4:0:-2] removes indices 4 and 2 (values 5 and 3), like Python's a[4:0:-2].
+        let mut v = Value::from(vec![1, 2, 3, 4, 5]);
+        v.del_slice(
+            Some(Value::from(4)),
+            Some(Value::from(0)),
+            Some(Value::from(-2)),
+        ).unwrap();
+        assert_eq!(&v.to_repr(), "[1, 2, 4]");
+    }
+
+    #[test]
+    fn test_hash_requires_frozen() {
+        let mut v = Value::from(vec![1, 2, 3]);
+        assert_eq!(v.get_hash(), Err(ValueError::IncorrectParameterType));
+        v.freeze();
+        assert!(v.get_hash().is_ok());
+    }
+
     #[test]
     fn test_arithmetic_on_list() {
         // [1, 2, 3] + [2, 3] == [1, 2, 3, 2, 3]